@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: 2026 Antoni Szymański
+// SPDX-License-Identifier: MPL-2.0
+
+#![cfg(unix)]
+
+mod common;
+
+use common::Fixture;
+use std::{fs, os::unix::fs::PermissionsExt};
+
+fn check(fixture: &Fixture, args: &[&str]) -> (bool, Vec<String>) {
+    let output = fixture.run(&[args, &["check"]].concat(), "");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let problems = stderr
+        .lines()
+        .filter(|line| line.starts_with("error: ") || line.starts_with("warning: "))
+        .map(|line| line.replace(&fixture.home().display().to_string(), "~"))
+        .collect();
+    (output.status.success(), problems)
+}
+
+#[test]
+fn clean_file_passes() {
+    let fixture = Fixture::new(
+        "check-clean",
+        "# work\nhttps://a:1@github.com/org\nhttps://a:2@github.com/org/repo\nhttps://b:3@github.com/org\n",
+    );
+    fs::set_permissions(
+        fixture.home().join(".git-credentials"),
+        fs::Permissions::from_mode(0o600),
+    )
+    .unwrap();
+    assert_eq!(check(&fixture, &[]), (true, vec![]));
+}
+
+#[test]
+fn problems_are_reported() {
+    let fixture = Fixture::new(
+        "check-problems",
+        "\
+https://a:1@github.com/org/repo
+https://a:2@github.com/org/repo.git
+https://a:1@github.com/org/rep*?priority=1
+https://github.com/org/other
+https://a:1@github.com/org/rep
+http://a:1@github.com/
+not a url
+",
+    );
+    let path = fixture.home().join(".git-credentials");
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+    let (success, problems) = check(&fixture, &[]);
+    assert!(!success);
+    assert_eq!(
+        problems,
+        [
+            "error: Failed to parse credentials from ~/.git-credentials:7",
+            "error: ~/.git-credentials: mode 644 lets others access it",
+            "error: ~/.git-credentials:1: shadowed by ~/.git-credentials:3",
+            "error: ~/.git-credentials:2: duplicate of ~/.git-credentials:1",
+            "warning: ~/.git-credentials:4: no password",
+            "error: ~/.git-credentials:5: shadowed by ~/.git-credentials:3",
+            "warning: ~/.git-credentials:6: scheme is http, not https",
+        ]
+    );
+}
+
+#[test]
+fn strict_fails_on_warnings() {
+    let fixture = Fixture::new("check-strict", "http://a:1@github.com/\n");
+    fs::set_permissions(
+        fixture.home().join(".git-credentials"),
+        fs::Permissions::from_mode(0o600),
+    )
+    .unwrap();
+    assert!(check(&fixture, &[]).0);
+    assert!(!check(&fixture, &["--strict"]).0);
+}
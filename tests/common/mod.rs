@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2026 Antoni Szymański
+// SPDX-License-Identifier: MPL-2.0
+
+#![allow(dead_code)]
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+/// An isolated home directory with a `.git-credentials` file in it.
+pub struct Fixture {
+    home: PathBuf,
+}
+
+impl Fixture {
+    pub fn new(name: &str, credentials: &str) -> Self {
+        let home = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+        let _ = fs::remove_dir_all(&home);
+        fs::create_dir_all(&home).unwrap();
+        let path = home.join(".git-credentials");
+        fs::write(&path, credentials).unwrap();
+        #[cfg(unix)]
+        fs::set_permissions(&path, std::os::unix::fs::PermissionsExt::from_mode(0o600)).unwrap();
+        Self { home }
+    }
+
+    pub fn home(&self) -> &Path {
+        &self.home
+    }
+
+    pub fn run(&self, args: &[&str], stdin: &str) -> Output {
+        self.run_with(args, stdin, |_| {})
+    }
+
+    /// Like [`Fixture::run`], but lets the caller adjust the command, e.g. its environment.
+    pub fn run_with(&self, args: &[&str], stdin: &str, configure: impl FnOnce(&mut Command)) -> Output {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_git-credential-store-pathprefix"));
+        command
+            .args(args)
+            .env("HOME", &self.home)
+            .env_remove("GIT_CREDENTIALS")
+            .env_remove("XDG_CONFIG_HOME")
+            .env_remove("XDG_STATE_HOME")
+            .current_dir(&self.home);
+        configure(&mut command);
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        // The helper may exit before reading stdin, e.g. when clap rejects the arguments.
+        if let Err(e) = child.stdin.take().unwrap().write_all(stdin.as_bytes()) {
+            assert_eq!(e.kind(), std::io::ErrorKind::BrokenPipe, "{e}");
+        }
+        child.wait_with_output().unwrap()
+    }
+
+    /// Runs `get` and returns its stdout.
+    pub fn get(&self, stdin: &str) -> String {
+        let output = self.run(&["get"], stdin);
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    pub fn credentials(&self) -> String {
+        fs::read_to_string(self.home.join(".git-credentials")).unwrap()
+    }
+}
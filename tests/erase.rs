@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2026 Antoni Szymański
+// SPDX-License-Identifier: MPL-2.0
+
+mod common;
+
+use common::Fixture;
+
+#[test]
+fn erase_removes_every_match() {
+    let fixture = Fixture::new(
+        "erase-matches",
+        "https://bot:1@github.com/org\nhttps://me:2@github.com/org/repo\nhttps://bot:3@github.com/org/repo\nhttps://bot:4@github.com/other\n",
+    );
+    let input = "protocol=https\nhost=github.com\npath=org/repo\nusername=bot\n\n";
+    assert!(fixture.run(&["erase"], input).status.success());
+    assert_eq!(
+        fixture.credentials(),
+        "https://me:2@github.com/org/repo\nhttps://bot:4@github.com/other\n"
+    );
+}
+
+#[test]
+fn erase_needs_protocol_and_host() {
+    let credentials = "https://bot:1@github.com/org\n";
+    let fixture = Fixture::new("erase-incomplete", credentials);
+    for input in ["host=github.com\n\n", "protocol=https\n\n", "\n"] {
+        assert!(fixture.run(&["erase"], input).status.success());
+    }
+    assert_eq!(fixture.credentials(), credentials);
+}
+
+#[cfg(unix)]
+#[test]
+fn erase_keeps_permissions() {
+    use std::{fs, os::unix::fs::PermissionsExt};
+    let fixture = Fixture::new(
+        "erase-permissions",
+        "https://bot:1@github.com/org\nhttps://me:2@gitlab.com\n",
+    );
+    let path = fixture.home().join(".git-credentials");
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+    assert!(
+        fixture
+            .run(&["erase"], "protocol=https\nhost=github.com\n\n")
+            .status
+            .success()
+    );
+    assert_eq!(fixture.credentials(), "https://me:2@gitlab.com\n");
+    assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o640);
+}
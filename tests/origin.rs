@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: 2026 Antoni Szymański
+// SPDX-License-Identifier: MPL-2.0
+
+mod common;
+
+use common::Fixture;
+
+#[test]
+fn origin_matrix() {
+    // (entry, protocol, host, should match)
+    let cases = [
+        ("https://github.com", "https", "github.com", true),
+        ("https://github.com", "http", "github.com", false),
+        ("https://github.com", "https", "gitlab.com", false),
+        ("http://github.com", "https", "gitlab.com", false),
+        ("https://github.com", "https", "GitHub.com", true),
+        ("https://GitHub.com", "https", "github.com", true),
+        ("https://github.com:443", "https", "github.com", true),
+        ("https://github.com", "https", "github.com:443", true),
+        ("http://github.com:80", "http", "github.com", true),
+        ("https://github.com", "https", "github.com:80", false),
+        ("https://example.com:8443", "https", "example.com", false),
+        ("https://example.com", "https", "example.com:8443", false),
+        ("https://example.com:8443", "https", "example.com:8443", true),
+        ("https://example.com:8443", "https", "example.com:8444", false),
+        ("https://[::1]:8080", "https", "[::1]:8080", true),
+        ("https://[::1]:8080", "https", "[::1]", false),
+        ("https://127.0.0.1", "https", "127.0.0.1", true),
+        ("https://127.0.0.1", "https", "127.0.0.2", false),
+        ("ssh://github.com", "https", "github.com", false),
+        ("https://*.corp.example", "https", "git.corp.example", true),
+        ("https://*.corp.example", "https", "Git.Corp.Example", true),
+        ("https://*.corp.example", "https", "corp.example", false),
+        ("https://*.corp.example", "https", "git.eu.corp.example", false),
+        ("https://git.*.corp.example", "https", "git.eu.corp.example", true),
+        ("https://git.*.corp.example", "https", "svn.eu.corp.example", false),
+        ("https://*.*.corp.example", "https", "git.us.corp.example", true),
+        ("https://*.corp.example:8443", "https", "git.corp.example", false),
+        ("http://*.corp.example", "https", "git.corp.example", false),
+    ];
+    for (i, (entry, protocol, host, expected)) in cases.into_iter().enumerate() {
+        let credentials = format!("{}\n", entry.replacen("://", "://u:p@", 1));
+        let fixture = Fixture::new(&format!("origin-matrix-{i}"), &credentials);
+        let output = fixture.get(&format!("protocol={protocol}\nhost={host}\n\n"));
+        assert_eq!(
+            !output.is_empty(),
+            expected,
+            "entry {entry:?}, protocol={protocol} host={host}"
+        );
+    }
+}
+
+#[test]
+fn missing_protocol_or_host_never_matches() {
+    let fixture = Fixture::new("origin-missing", "https://github.com\n");
+    assert_eq!(fixture.get("host=github.com\n\n"), "");
+    assert_eq!(fixture.get("protocol=https\n\n"), "");
+}
+
+#[test]
+fn get_and_store_round_trip() {
+    let credentials = "https://a:b@example.com:8443/x\n";
+    let fixture = Fixture::new("origin-round-trip", credentials);
+    let request = "protocol=https\nhost=example.com:8443\npath=x/y\n";
+    let output = fixture.get(&format!("{request}\n"));
+    // Git keeps the host and port it asked for.
+    assert_eq!(output, "username=a\npassword=b\n");
+    // And then stores the credential it used, which must not add an entry for another port.
+    assert!(
+        fixture
+            .run(&["store"], &format!("{request}{output}\n"))
+            .status
+            .success()
+    );
+    assert_eq!(fixture.credentials(), credentials);
+}
+
+#[test]
+fn erase_only_removes_same_origin() {
+    let fixture = Fixture::new(
+        "origin-erase",
+        "https://a:1@github.com/org\nhttp://a:1@github.com/org\nhttps://a:1@example.com/org\n",
+    );
+    let output = fixture.run(&["erase"], "protocol=https\nhost=github.com\npath=org/repo\n\n");
+    assert!(output.status.success());
+    assert_eq!(
+        fixture.credentials(),
+        "http://a:1@github.com/org\nhttps://a:1@example.com/org\n"
+    );
+}
+
+#[test]
+fn exact_host_wins_over_wildcard() {
+    let fixture = Fixture::new(
+        "origin-wildcard-specificity",
+        "https://wild:1@*.corp.example/group\nhttps://exact:2@git.corp.example/group\n",
+    );
+    let output = fixture.get("protocol=https\nhost=git.corp.example\npath=group/repo.git\n\n");
+    assert!(output.contains("username=exact\n"), "{output}");
+}
+
+#[test]
+fn get_doesnt_return_patterns() {
+    let fixture = Fixture::new("origin-wildcard-get", "https://bot:1@*.corp.example/org/*-infra\n");
+    let output = fixture.get("protocol=https\nhost=git.corp.example\npath=org/app-infra.git\n\n");
+    assert_eq!(output, "username=bot\npassword=1\n");
+}